@@ -0,0 +1,140 @@
+mod v3;
+mod v5;
+mod v6;
+
+pub use self::v3::SensorDataV3;
+pub use self::v5::SensorDataV5;
+pub use self::v6::SensorDataV6;
+
+/// Measurement values from a RuuviTag sensor.
+///
+/// Which fields are present depends on the data format the tag broadcasts; a field is `None`
+/// when the format does not carry that measurement or the sensor reports it as unavailable. The
+/// storage is private; read it through the per-quantity traits in the crate root, e.g.
+/// [`Temperature`](crate::Temperature) or [`Humidity`](crate::Humidity).
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SensorValues {
+    pub(crate) humidity: Option<u32>,
+    pub(crate) temperature: Option<i32>,
+    pub(crate) pressure: Option<u32>,
+    pub(crate) acceleration: Option<AccelerationVector>,
+    pub(crate) battery_potential: Option<u16>,
+    pub(crate) tx_power: Option<i8>,
+    pub(crate) movement_counter: Option<u8>,
+    pub(crate) measurement_sequence_number: Option<u16>,
+    pub(crate) mac_address: Option<[u8; 6]>,
+    pub(crate) co2_ppm: Option<u16>,
+    pub(crate) pm2_5_ugm3: Option<u16>,
+    pub(crate) voc_index: Option<u16>,
+    pub(crate) nox_index: Option<u16>,
+}
+
+/// The Ruuvi manufacturer id used to identify the advertisement data as belonging to a RuuviTag.
+const RUUVI_MANUFACTURER_ID: u16 = 0x0499;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    UnknownManufacturerId(u16),
+    UnsupportedFormatVersion(u8),
+    InvalidValueLength {
+        version: u8,
+        length: usize,
+        expected: usize,
+    },
+}
+
+impl SensorValues {
+    /// Parses sensor values out of the manufacturer specific data of a Bluetooth advertisement.
+    ///
+    /// `id` is the manufacturer id and `value` is the manufacturer specific data that follows it.
+    pub fn from_manufacturer_specific_data(id: u16, value: &[u8]) -> Result<Self, ParseError> {
+        if id != RUUVI_MANUFACTURER_ID {
+            return Err(ParseError::UnknownManufacturerId(id));
+        }
+
+        match value.first() {
+            Some(3) => SensorDataV3::from_manufacturer_specific_data(value).map(Into::into),
+            Some(5) => SensorDataV5::from_manufacturer_specific_data(value).map(Into::into),
+            Some(6) => SensorDataV6::from_manufacturer_specific_data(value).map(Into::into),
+            Some(&version) => Err(ParseError::UnsupportedFormatVersion(version)),
+            None => Err(ParseError::InvalidValueLength {
+                version: 0,
+                length: 0,
+                expected: 1,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AccelerationVector(pub i16, pub i16, pub i16);
+
+impl AccelerationVector {
+    /// Returns the total acceleration magnitude in milli-G, computed as the Euclidean norm of
+    /// the three axes using integer arithmetic so it works without floating point support.
+    pub fn magnitude(&self) -> u32 {
+        let x = i64::from(self.0);
+        let y = i64::from(self.1);
+        let z = i64::from(self.2);
+        isqrt((x * x + y * y + z * z) as u64) as u32
+    }
+}
+
+/// Integer square root computed with Newton's method, rounding down.
+fn isqrt(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn none_if<T: PartialEq>(value: T, sentinel: T) -> Option<T> {
+    if value == sentinel {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_manufacturer_specific_data_rejects_unknown_manufacturer_id() {
+        let value = vec![3, 0x17, 0x01, 0x45, 0x35, 0x58, 0x03, 0xE8, 0x04, 0xE7, 0x05, 0xE6, 0x08, 0x86];
+        let result = SensorValues::from_manufacturer_specific_data(0x1234, &value);
+        assert_eq!(result, Err(ParseError::UnknownManufacturerId(0x1234)));
+    }
+
+    #[test]
+    fn from_manufacturer_specific_data_rejects_unsupported_version() {
+        let result = SensorValues::from_manufacturer_specific_data(0x0499, &[7]);
+        assert_eq!(result, Err(ParseError::UnsupportedFormatVersion(7)));
+    }
+
+    #[test]
+    fn acceleration_vector_magnitude_of_a_single_axis() {
+        let vector = AccelerationVector(1000, 0, 0);
+        assert_eq!(vector.magnitude(), 1000);
+    }
+
+    #[test]
+    fn acceleration_vector_magnitude_of_three_axes() {
+        let vector = AccelerationVector(1000, 1255, 1510);
+        assert_eq!(vector.magnitude(), 2203);
+    }
+
+    #[test]
+    fn acceleration_vector_magnitude_ignores_sign() {
+        let vector = AccelerationVector(-3, -4, 0);
+        assert_eq!(vector.magnitude(), 5);
+    }
+}
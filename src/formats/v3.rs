@@ -1,4 +1,4 @@
-use sensordata::{ParseError, SensorData};
+use super::{AccelerationVector, ParseError, SensorValues};
 
 #[derive(Debug, PartialEq)]
 pub struct SensorDataV3 {
@@ -36,9 +36,25 @@ impl SensorDataV3 {
     }
 }
 
-impl Into<SensorData> for SensorDataV3 {
-    fn into(self) -> SensorData {
-        unimplemented!()
+impl From<SensorDataV3> for SensorValues {
+    fn from(value: SensorDataV3) -> Self {
+        let sign = if value.temperature & 0x8000 != 0 { -1 } else { 1 };
+        let integer_part = i32::from((value.temperature >> 8) & 0x7F);
+        let fractional_part = i32::from(value.temperature & 0xFF);
+        let temperature = sign * (integer_part * 1000 + fractional_part * 10);
+
+        SensorValues {
+            humidity: Some(u32::from(value.humidity) * 5_000),
+            temperature: Some(temperature),
+            pressure: Some(u32::from(value.pressure) + 50_000),
+            acceleration: Some(AccelerationVector(
+                value.acceleration.0,
+                value.acceleration.1,
+                value.acceleration.2,
+            )),
+            battery_potential: Some(value.battery_potential),
+            ..Default::default()
+        }
     }
 }
 
@@ -52,8 +68,6 @@ fn i16_from_two_bytes(b1: u8, b2: u8) -> i16 {
 
 #[cfg(test)]
 mod tests {
-    use sensordata::AccelerationVector;
-
     use super::*;
 
     #[test]
@@ -98,12 +112,13 @@ mod tests {
 
         assert_eq!(
             result.map(|data| data.into()),
-            Ok(SensorData {
+            Ok(SensorValues {
                 humidity: Some(115_000),
                 temperature: Some(1690),
                 pressure: Some(63656),
                 acceleration: Some(AccelerationVector(1000, 1255, 1510)),
-                battery_potential: Some(2182)
+                battery_potential: Some(2182),
+                ..Default::default()
             })
         );
     }
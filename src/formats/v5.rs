@@ -0,0 +1,146 @@
+use super::{none_if, AccelerationVector, ParseError, SensorValues};
+
+#[derive(Debug, PartialEq)]
+pub struct SensorDataV5 {
+    humidity: u16,
+    temperature: i16,
+    pressure: u16,
+    acceleration: AccelerationVectorV5,
+    battery_potential: u16,
+    tx_power: u8,
+    movement_counter: u8,
+    measurement_sequence_number: u16,
+    mac_address: [u8; 6],
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AccelerationVectorV5(i16, i16, i16);
+
+impl SensorDataV5 {
+    pub fn from_manufacturer_specific_data(value: &[u8]) -> Result<Self, ParseError> {
+        if value.len() == 24 {
+            let power_info = u16_from_two_bytes(value[13], value[14]);
+
+            Ok(Self {
+                temperature: i16_from_two_bytes(value[1], value[2]),
+                humidity: u16_from_two_bytes(value[3], value[4]),
+                pressure: u16_from_two_bytes(value[5], value[6]),
+                acceleration: AccelerationVectorV5(
+                    i16_from_two_bytes(value[7], value[8]),
+                    i16_from_two_bytes(value[9], value[10]),
+                    i16_from_two_bytes(value[11], value[12]),
+                ),
+                battery_potential: power_info >> 5,
+                tx_power: (power_info & 0x1F) as u8,
+                movement_counter: value[15],
+                measurement_sequence_number: u16_from_two_bytes(value[16], value[17]),
+                mac_address: [
+                    value[18], value[19], value[20], value[21], value[22], value[23],
+                ],
+            })
+        } else {
+            Err(ParseError::InvalidValueLength {
+                version: 5,
+                length: value.len(),
+                expected: 24,
+            })
+        }
+    }
+}
+
+impl From<SensorDataV5> for SensorValues {
+    fn from(value: SensorDataV5) -> Self {
+        let acceleration = none_if(value.acceleration.0, -0x8000)
+            .and_then(|x| none_if(value.acceleration.1, -0x8000).map(|y| (x, y)))
+            .and_then(|(x, y)| none_if(value.acceleration.2, -0x8000).map(|z| (x, y, z)))
+            .map(|(x, y, z)| AccelerationVector(x, y, z));
+
+        SensorValues {
+            humidity: none_if(value.humidity, 0xFFFF).map(|humidity| u32::from(humidity) * 25),
+            temperature: none_if(value.temperature, -0x8000)
+                .map(|temperature| i32::from(temperature) * 5),
+            pressure: none_if(value.pressure, 0xFFFF)
+                .map(|pressure| u32::from(pressure) + 50_000),
+            acceleration,
+            battery_potential: none_if(value.battery_potential, 0x7FF)
+                .map(|battery_potential| battery_potential + 1600),
+            tx_power: none_if(value.tx_power, 0x1F)
+                .map(|tx_power| 2 * tx_power as i8 - 40),
+            movement_counter: none_if(value.movement_counter, 0xFF),
+            measurement_sequence_number: none_if(value.measurement_sequence_number, 0xFFFF),
+            mac_address: Some(value.mac_address),
+            ..Default::default()
+        }
+    }
+}
+
+fn u16_from_two_bytes(b1: u8, b2: u8) -> u16 {
+    ((b1 as u16) << 8) | b2 as u16
+}
+
+fn i16_from_two_bytes(b1: u8, b2: u8) -> i16 {
+    u16_from_two_bytes(b1, b2) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_5_data_with_invalid_length() {
+        let value = vec![5, 1, 2, 3, 4, 5];
+        let result = SensorDataV5::from_manufacturer_specific_data(&value);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidValueLength {
+                version: 5,
+                length: 6,
+                expected: 24
+            })
+        );
+    }
+
+    #[test]
+    fn parse_valid_version_5_data() {
+        let value = vec![
+            0x05, 0x12, 0xFC, 0x53, 0x94, 0xC3, 0x7C, 0x00, 0x04, 0xFF, 0xFF, 0x04, 0x0C, 0x05,
+            0x86, 0x42, 0x00, 0xCD, 0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F,
+        ];
+        let result = SensorDataV5::from_manufacturer_specific_data(&value);
+        assert_eq!(
+            result,
+            Ok(SensorDataV5 {
+                temperature: 0x12FC,
+                humidity: 0x5394,
+                pressure: 0xC37C,
+                acceleration: AccelerationVectorV5(4, -1, 0x040C),
+                battery_potential: 44,
+                tx_power: 6,
+                movement_counter: 0x42,
+                measurement_sequence_number: 0x00CD,
+                mac_address: [0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F],
+            })
+        );
+    }
+
+    #[test]
+    fn sentinel_values_are_parsed_as_not_available() {
+        let value = vec![
+            0x05, 0x80, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0xFF,
+            0xFF, 0xFF, 0xFF, 0xFF, 0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F,
+        ];
+        let result: SensorValues = SensorDataV5::from_manufacturer_specific_data(&value)
+            .unwrap()
+            .into();
+
+        assert_eq!(result.temperature, None);
+        assert_eq!(result.humidity, None);
+        assert_eq!(result.pressure, None);
+        assert_eq!(result.acceleration, None);
+        assert_eq!(result.battery_potential, None);
+        assert_eq!(result.tx_power, None);
+        assert_eq!(result.movement_counter, None);
+        assert_eq!(result.measurement_sequence_number, None);
+        assert_eq!(result.mac_address, Some([0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F]));
+    }
+}
@@ -0,0 +1,115 @@
+use super::{none_if, ParseError, SensorValues};
+
+/// Data Format 6: the air-quality payload carried by environmental RuuviTags, in addition to
+/// the classic temperature/humidity/pressure set.
+///
+/// The layout is nine bytes: the format version, followed by four big-endian `u16` channels
+/// (CO2 in ppm, PM2.5 in µg/m³, VOC index and NOx index), each using `0xFFFF` as its
+/// "not available" sentinel.
+#[derive(Debug, PartialEq)]
+pub struct SensorDataV6 {
+    co2_ppm: u16,
+    pm2_5_ugm3: u16,
+    voc_index: u16,
+    nox_index: u16,
+}
+
+impl SensorDataV6 {
+    pub fn from_manufacturer_specific_data(value: &[u8]) -> Result<Self, ParseError> {
+        if value.len() == 9 {
+            Ok(Self {
+                co2_ppm: u16_from_two_bytes(value[1], value[2]),
+                pm2_5_ugm3: u16_from_two_bytes(value[3], value[4]),
+                voc_index: u16_from_two_bytes(value[5], value[6]),
+                nox_index: u16_from_two_bytes(value[7], value[8]),
+            })
+        } else {
+            Err(ParseError::InvalidValueLength {
+                version: 6,
+                length: value.len(),
+                expected: 9,
+            })
+        }
+    }
+}
+
+impl From<SensorDataV6> for SensorValues {
+    fn from(value: SensorDataV6) -> Self {
+        SensorValues {
+            co2_ppm: none_if(value.co2_ppm, 0xFFFF),
+            pm2_5_ugm3: none_if(value.pm2_5_ugm3, 0xFFFF),
+            voc_index: none_if(value.voc_index, 0xFFFF),
+            nox_index: none_if(value.nox_index, 0xFFFF),
+            ..Default::default()
+        }
+    }
+}
+
+fn u16_from_two_bytes(b1: u8, b2: u8) -> u16 {
+    ((b1 as u16) << 8) | b2 as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_6_data_with_invalid_length() {
+        let value = vec![6, 1, 2, 3, 4];
+        let result = SensorDataV6::from_manufacturer_specific_data(&value);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidValueLength {
+                version: 6,
+                length: 5,
+                expected: 9
+            })
+        );
+    }
+
+    #[test]
+    fn parse_valid_version_6_data() {
+        let value = vec![6, 0x01, 0xF4, 0x00, 0x0C, 0x00, 0x32, 0x00, 0x14];
+        let result = SensorDataV6::from_manufacturer_specific_data(&value);
+        assert_eq!(
+            result,
+            Ok(SensorDataV6 {
+                co2_ppm: 500,
+                pm2_5_ugm3: 12,
+                voc_index: 50,
+                nox_index: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_version_6_into_generic_structure() {
+        let value = vec![6, 0x01, 0xF4, 0x00, 0x0C, 0x00, 0x32, 0x00, 0x14];
+        let result = SensorDataV6::from_manufacturer_specific_data(&value);
+        assert!(result.is_ok());
+
+        assert_eq!(
+            result.map(|data| data.into()),
+            Ok(SensorValues {
+                co2_ppm: Some(500),
+                pm2_5_ugm3: Some(12),
+                voc_index: Some(50),
+                nox_index: Some(20),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn sentinel_values_are_parsed_as_not_available() {
+        let value = vec![6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let result: SensorValues = SensorDataV6::from_manufacturer_specific_data(&value)
+            .unwrap()
+            .into();
+
+        assert_eq!(result.co2_ppm, None);
+        assert_eq!(result.pm2_5_ugm3, None);
+        assert_eq!(result.voc_index, None);
+        assert_eq!(result.nox_index, None);
+    }
+}
@@ -22,9 +22,13 @@ let result = SensorValues::from_manufacturer_specific_data(id, value);
 assert_eq!(result, Err(ParseError::UnsupportedFormatVersion(7)));
 ```
 
-A successful parse returns a `SensorValue` structure with a set of values.
+A successful parse returns a `SensorValue` structure with a set of values, exposed through the
+per-quantity traits rather than public fields.
 ```rust
-use ruuvi_sensor_protocol::{AccelerationVector, SensorValues};
+use ruuvi_sensor_protocol::{
+    Acceleration, AccelerationVector, BatteryPotential, Humidity, Pressure, SensorValues,
+    Temperature,
+};
 # use ruuvi_sensor_protocol::ParseError;
 
 let id = 0x0499;
@@ -33,11 +37,14 @@ let value = &[
 ];
 let values = SensorValues::from_manufacturer_specific_data(id, value)?;
 
-assert_eq!(values.humidity, Some(115_000));
-assert_eq!(values.temperature, Some(1690));
-assert_eq!(values.pressure, Some(63656));
-assert_eq!(values.acceleration, Some(AccelerationVector(1000, 1255, 1510)));
-assert_eq!(values.battery_potential, Some(2182));
+assert_eq!(values.humidity_as_ppm(), Some(115_000));
+assert_eq!(values.temperature_as_millicelsius(), Some(1690));
+assert_eq!(values.pressure_as_pascals(), Some(63656));
+assert_eq!(
+    values.acceleration_vector_as_milli_g(),
+    Some(AccelerationVector(1000, 1255, 1510))
+);
+assert_eq!(values.battery_potential_as_millivolts(), Some(2182));
 # Ok::<(), ParseError>(())
 ```
 
@@ -48,50 +55,17 @@ See [`SensorValues`](struct.SensorValues.html) documentation for a description o
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod formats;
+mod traits;
+#[cfg(feature = "uom")]
+mod units;
 
 pub use crate::formats::{AccelerationVector, ParseError, SensorValues};
-
-pub trait Temperature {
-    const ZERO_CELSIUS_IN_MILLIKELVINS: u32 = 273_1500;
-
-    /// Returns temperature in milli-kelvins if a temperature reading is available.
-    fn temperature_as_millikelvins(&self) -> Option<u32>;
-
-    /// Returns temperature in milli-Celsius if a temperature reading is available.
-    fn temperature_as_millicelsius(&self) -> Option<i32> {
-        self.temperature_as_millikelvins().map(|temperature| {
-            temperature as i32 - Self::ZERO_CELSIUS_IN_MILLIKELVINS as i32
-        })
-    }
-}
-
-mod tests {
-    use super::*;
-
-    #[allow(dead_code)]
-    struct Value {
-        temperature: Option<u32>
-    }
-
-    impl Temperature for Value {
-        fn temperature_as_millikelvins(&self) -> Option<u32> {
-            self.temperature
-        }
-    }
-
-    macro_rules! test_kelvins_to_celcius_conversion {
-        ($name: ident, $milli_kelvins: expr, $milli_celsius: expr) => {
-            #[test]
-            fn $name() {
-                let value = Value { temperature: $milli_kelvins };
-                assert_eq!(value.temperature_as_millicelsius(), $milli_celsius);
-            }
-        }
-    }
-
-    test_kelvins_to_celcius_conversion!(zero_kelvins, Some(0), Some(-273_1500));
-    test_kelvins_to_celcius_conversion!(zero_celsius, Some(273_1500), Some(0));
-    test_kelvins_to_celcius_conversion!(sub_zero_celsius_1, Some(263_0800), Some(-10_0700));
-    test_kelvins_to_celcius_conversion!(sub_zero_celsius_2, Some(194_9240), Some(-78_2260));
-    test_kelvins_to_celcius_conversion!(no_temperature, None, None);
-}
+pub use crate::traits::{
+    Acceleration, BatteryPotential, CarbonDioxide, Humidity, MacAddress,
+    MeasurementSequenceNumber, MovementCounter, NitrogenOxides, ParticulateMatter, Pressure,
+    Temperature, TransmitterPower, VolatileOrganicCompounds,
+};
+#[cfg(feature = "uom")]
+pub use crate::units::{
+    AccelerationExt, BatteryPotentialExt, PressureExt, TemperatureExt, TransmitterPowerExt,
+};
@@ -0,0 +1,314 @@
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+
+use crate::formats::{AccelerationVector, SensorValues};
+
+pub trait Temperature {
+    const ZERO_CELSIUS_IN_MILLIKELVINS: u32 = 273_1500;
+
+    /// Returns temperature in milli-kelvins if a temperature reading is available.
+    fn temperature_as_millikelvins(&self) -> Option<u32>;
+
+    /// Returns temperature in milli-Celsius if a temperature reading is available.
+    fn temperature_as_millicelsius(&self) -> Option<i32> {
+        self.temperature_as_millikelvins().map(|temperature| {
+            temperature as i32 - Self::ZERO_CELSIUS_IN_MILLIKELVINS as i32
+        })
+    }
+
+    /// Returns temperature in degrees Celsius if a temperature reading is available.
+    #[cfg(feature = "std")]
+    fn temperature_as_celsius(&self) -> Option<f32> {
+        self.temperature_as_millicelsius()
+            .map(|millicelsius| millicelsius as f32 / 1000.0)
+    }
+
+    /// Returns temperature in degrees Fahrenheit if a temperature reading is available.
+    #[cfg(feature = "std")]
+    fn temperature_as_fahrenheit(&self) -> Option<f32> {
+        self.temperature_as_celsius()
+            .map(|celsius| celsius * 9.0 / 5.0 + 32.0)
+    }
+
+    /// Returns whether the reading falls within `range`, given in degrees Celsius, or `None`
+    /// if no temperature reading is available.
+    #[cfg(feature = "std")]
+    fn within(&self, range: RangeInclusive<f32>) -> Option<bool> {
+        self.temperature_as_celsius()
+            .map(|celsius| range.contains(&celsius))
+    }
+}
+
+pub trait Humidity {
+    /// Returns relative humidity in parts per million if a humidity reading is available.
+    fn humidity_as_ppm(&self) -> Option<u32>;
+}
+
+pub trait Pressure {
+    /// Returns atmospheric pressure in pascals if a pressure reading is available.
+    fn pressure_as_pascals(&self) -> Option<u32>;
+}
+
+pub trait Acceleration {
+    /// Returns the acceleration vector in milli-G if an acceleration reading is available.
+    fn acceleration_vector_as_milli_g(&self) -> Option<AccelerationVector>;
+
+    /// Returns the total acceleration magnitude in milli-G if an acceleration reading is
+    /// available.
+    fn acceleration_magnitude_as_milli_g(&self) -> Option<u32> {
+        self.acceleration_vector_as_milli_g()
+            .map(|vector| vector.magnitude())
+    }
+}
+
+pub trait BatteryPotential {
+    /// Returns battery potential in millivolts if a battery reading is available.
+    fn battery_potential_as_millivolts(&self) -> Option<u16>;
+}
+
+pub trait MacAddress {
+    /// Returns the MAC address of the sensor if it was included in the advertisement.
+    fn mac_address(&self) -> Option<[u8; 6]>;
+}
+
+pub trait MeasurementSequenceNumber {
+    /// Returns the measurement sequence number if it was included in the advertisement.
+    fn measurement_sequence_number(&self) -> Option<u16>;
+}
+
+pub trait MovementCounter {
+    /// Returns the number of times the sensor has detected movement, if known.
+    fn movement_counter(&self) -> Option<u8>;
+}
+
+pub trait CarbonDioxide {
+    /// Returns the CO2 concentration in parts per million if a reading is available.
+    fn co2_as_ppm(&self) -> Option<u16>;
+}
+
+pub trait ParticulateMatter {
+    /// Returns the PM2.5 concentration in micrograms per cubic metre if a reading is available.
+    fn pm2_5_as_micrograms_per_cubic_metre(&self) -> Option<u16>;
+}
+
+pub trait VolatileOrganicCompounds {
+    /// Returns the VOC index if a reading is available.
+    fn voc_index(&self) -> Option<u16>;
+}
+
+pub trait NitrogenOxides {
+    /// Returns the NOx index if a reading is available.
+    fn nox_index(&self) -> Option<u16>;
+}
+
+pub trait TransmitterPower {
+    /// Returns transmitter power in dBm if it was included in the advertisement.
+    fn tx_power_as_dbm(&self) -> Option<i8>;
+}
+
+impl Temperature for SensorValues {
+    fn temperature_as_millikelvins(&self) -> Option<u32> {
+        self.temperature
+            .map(|temperature| (temperature + Self::ZERO_CELSIUS_IN_MILLIKELVINS as i32) as u32)
+    }
+}
+
+impl Humidity for SensorValues {
+    fn humidity_as_ppm(&self) -> Option<u32> {
+        self.humidity
+    }
+}
+
+impl Pressure for SensorValues {
+    fn pressure_as_pascals(&self) -> Option<u32> {
+        self.pressure
+    }
+}
+
+impl Acceleration for SensorValues {
+    fn acceleration_vector_as_milli_g(&self) -> Option<AccelerationVector> {
+        self.acceleration
+    }
+}
+
+impl BatteryPotential for SensorValues {
+    fn battery_potential_as_millivolts(&self) -> Option<u16> {
+        self.battery_potential
+    }
+}
+
+impl MacAddress for SensorValues {
+    fn mac_address(&self) -> Option<[u8; 6]> {
+        self.mac_address
+    }
+}
+
+impl MeasurementSequenceNumber for SensorValues {
+    fn measurement_sequence_number(&self) -> Option<u16> {
+        self.measurement_sequence_number
+    }
+}
+
+impl MovementCounter for SensorValues {
+    fn movement_counter(&self) -> Option<u8> {
+        self.movement_counter
+    }
+}
+
+impl TransmitterPower for SensorValues {
+    fn tx_power_as_dbm(&self) -> Option<i8> {
+        self.tx_power
+    }
+}
+
+impl CarbonDioxide for SensorValues {
+    fn co2_as_ppm(&self) -> Option<u16> {
+        self.co2_ppm
+    }
+}
+
+impl ParticulateMatter for SensorValues {
+    fn pm2_5_as_micrograms_per_cubic_metre(&self) -> Option<u16> {
+        self.pm2_5_ugm3
+    }
+}
+
+impl VolatileOrganicCompounds for SensorValues {
+    fn voc_index(&self) -> Option<u16> {
+        self.voc_index
+    }
+}
+
+impl NitrogenOxides for SensorValues {
+    fn nox_index(&self) -> Option<u16> {
+        self.nox_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    struct Value {
+        temperature: Option<u32>,
+    }
+
+    impl Temperature for Value {
+        fn temperature_as_millikelvins(&self) -> Option<u32> {
+            self.temperature
+        }
+    }
+
+    macro_rules! test_kelvins_to_celcius_conversion {
+        ($name: ident, $milli_kelvins: expr, $milli_celsius: expr) => {
+            #[test]
+            fn $name() {
+                let value = Value {
+                    temperature: $milli_kelvins,
+                };
+                assert_eq!(value.temperature_as_millicelsius(), $milli_celsius);
+            }
+        };
+    }
+
+    test_kelvins_to_celcius_conversion!(zero_kelvins, Some(0), Some(-273_1500));
+    test_kelvins_to_celcius_conversion!(zero_celsius, Some(273_1500), Some(0));
+    test_kelvins_to_celcius_conversion!(sub_zero_celsius_1, Some(263_0800), Some(-10_0700));
+    test_kelvins_to_celcius_conversion!(sub_zero_celsius_2, Some(194_9240), Some(-78_2260));
+    test_kelvins_to_celcius_conversion!(no_temperature, None, None);
+
+    #[test]
+    fn sensor_values_temperature_round_trips_through_millikelvins() {
+        let values = SensorValues {
+            temperature: Some(1690),
+            ..Default::default()
+        };
+        assert_eq!(values.temperature_as_millicelsius(), Some(1690));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn celsius_and_fahrenheit_are_derived_from_millicelsius() {
+        let value = Value {
+            temperature: Some(2_733_190),
+        };
+        assert_eq!(value.temperature_as_celsius(), Some(1.69));
+        assert_eq!(value.temperature_as_fahrenheit(), Some(35.042));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn celsius_and_fahrenheit_are_none_without_a_reading() {
+        let value = Value { temperature: None };
+        assert_eq!(value.temperature_as_celsius(), None);
+        assert_eq!(value.temperature_as_fahrenheit(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn within_reports_whether_the_reading_falls_in_the_given_celsius_range() {
+        let value = Value {
+            temperature: Some(2_733_190),
+        };
+        assert_eq!(value.within(0.0..=10.0), Some(true));
+        assert_eq!(value.within(10.0..=20.0), Some(false));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn within_is_none_without_a_reading() {
+        let value = Value { temperature: None };
+        assert_eq!(value.within(0.0..=10.0), None);
+    }
+
+    #[test]
+    fn sensor_values_exposes_format_5_fields_through_traits() {
+        let values = SensorValues {
+            tx_power: Some(4),
+            movement_counter: Some(42),
+            measurement_sequence_number: Some(205),
+            mac_address: Some([0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F]),
+            ..Default::default()
+        };
+
+        assert_eq!(values.tx_power_as_dbm(), Some(4));
+        assert_eq!(values.movement_counter(), Some(42));
+        assert_eq!(values.measurement_sequence_number(), Some(205));
+        assert_eq!(
+            values.mac_address(),
+            Some([0xCB, 0xB8, 0x33, 0x4C, 0x88, 0x4F])
+        );
+    }
+
+    #[test]
+    fn sensor_values_acceleration_magnitude_is_derived_from_the_vector() {
+        let values = SensorValues {
+            acceleration: Some(AccelerationVector(1000, 1255, 1510)),
+            ..Default::default()
+        };
+        assert_eq!(values.acceleration_magnitude_as_milli_g(), Some(2203));
+    }
+
+    #[test]
+    fn sensor_values_acceleration_magnitude_is_none_without_a_reading() {
+        let values = SensorValues::default();
+        assert_eq!(values.acceleration_magnitude_as_milli_g(), None);
+    }
+
+    #[test]
+    fn sensor_values_exposes_air_quality_fields_through_traits() {
+        let values = SensorValues {
+            co2_ppm: Some(500),
+            pm2_5_ugm3: Some(12),
+            voc_index: Some(50),
+            nox_index: Some(20),
+            ..Default::default()
+        };
+
+        assert_eq!(values.co2_as_ppm(), Some(500));
+        assert_eq!(values.pm2_5_as_micrograms_per_cubic_metre(), Some(12));
+        assert_eq!(values.voc_index(), Some(50));
+        assert_eq!(values.nox_index(), Some(20));
+    }
+}
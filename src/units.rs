@@ -0,0 +1,215 @@
+//! Optional strongly-typed physical quantity accessors, enabled by the `uom` feature.
+//!
+//! These are extension traits rather than additional methods on [`crate::Temperature`] and
+//! friends so that enabling the feature never forces a `uom` type into the default, dependency-
+//! free build.
+
+use uom::si::acceleration::standard_gravity;
+use uom::si::electric_potential::millivolt;
+use uom::si::f64::{
+    Acceleration as UomAcceleration, ElectricPotential, Pressure as UomPressure, Ratio,
+    ThermodynamicTemperature,
+};
+use uom::si::pressure::pascal;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::{Acceleration, BatteryPotential, Pressure, Temperature, TransmitterPower};
+
+/// Adds a [`uom`](https://docs.rs/uom) accessor to [`Temperature`].
+pub trait TemperatureExt: Temperature {
+    /// Returns temperature as a dimensioned [`ThermodynamicTemperature`] if a reading is
+    /// available.
+    fn temperature_as_uom(&self) -> Option<ThermodynamicTemperature> {
+        self.temperature_as_millikelvins()
+            .map(|millikelvins| ThermodynamicTemperature::new::<kelvin>(millikelvins as f64 / 1000.0))
+    }
+}
+
+impl<T: Temperature + ?Sized> TemperatureExt for T {}
+
+/// Adds a [`uom`](https://docs.rs/uom) accessor to [`Pressure`].
+pub trait PressureExt: Pressure {
+    /// Returns pressure as a dimensioned [`UomPressure`] if a reading is available.
+    fn pressure_as_uom(&self) -> Option<UomPressure> {
+        self.pressure_as_pascals()
+            .map(|pascals| UomPressure::new::<pascal>(f64::from(pascals)))
+    }
+}
+
+impl<T: Pressure + ?Sized> PressureExt for T {}
+
+/// Adds a [`uom`](https://docs.rs/uom) accessor to [`BatteryPotential`].
+pub trait BatteryPotentialExt: BatteryPotential {
+    /// Returns battery potential as a dimensioned [`ElectricPotential`] if a reading is
+    /// available.
+    fn battery_potential_as_uom(&self) -> Option<ElectricPotential> {
+        self.battery_potential_as_millivolts()
+            .map(|millivolts| ElectricPotential::new::<millivolt>(f64::from(millivolts)))
+    }
+}
+
+impl<T: BatteryPotential + ?Sized> BatteryPotentialExt for T {}
+
+/// Adds a [`uom`](https://docs.rs/uom) accessor to [`TransmitterPower`].
+pub trait TransmitterPowerExt: TransmitterPower {
+    /// Returns transmitter power as a dimensionless power [`Ratio`] relative to 1 mW, if a
+    /// reading is available.
+    fn tx_power_as_uom(&self) -> Option<Ratio> {
+        self.tx_power_as_dbm()
+            .map(|dbm| Ratio::new::<ratio>(libm::pow(10.0, f64::from(dbm) / 10.0)))
+    }
+}
+
+impl<T: TransmitterPower + ?Sized> TransmitterPowerExt for T {}
+
+/// Adds a [`uom`](https://docs.rs/uom) accessor to [`Acceleration`].
+pub trait AccelerationExt: Acceleration {
+    /// Returns the acceleration vector as dimensioned [`UomAcceleration`] components if a
+    /// reading is available.
+    fn acceleration_vector_as_uom(&self) -> Option<(UomAcceleration, UomAcceleration, UomAcceleration)> {
+        self.acceleration_vector_as_milli_g().map(|vector| {
+            let milli_g_as_uom =
+                |milli_g: i16| UomAcceleration::new::<standard_gravity>(f64::from(milli_g) / 1000.0);
+            (milli_g_as_uom(vector.0), milli_g_as_uom(vector.1), milli_g_as_uom(vector.2))
+        })
+    }
+}
+
+impl<T: Acceleration + ?Sized> AccelerationExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AccelerationVector;
+
+    #[derive(Default)]
+    struct Value {
+        temperature: Option<u32>,
+        pressure: Option<u32>,
+        battery_potential: Option<u16>,
+        tx_power: Option<i8>,
+        acceleration: Option<AccelerationVector>,
+    }
+
+    impl Temperature for Value {
+        fn temperature_as_millikelvins(&self) -> Option<u32> {
+            self.temperature
+        }
+    }
+
+    impl Pressure for Value {
+        fn pressure_as_pascals(&self) -> Option<u32> {
+            self.pressure
+        }
+    }
+
+    impl BatteryPotential for Value {
+        fn battery_potential_as_millivolts(&self) -> Option<u16> {
+            self.battery_potential
+        }
+    }
+
+    impl TransmitterPower for Value {
+        fn tx_power_as_dbm(&self) -> Option<i8> {
+            self.tx_power
+        }
+    }
+
+    impl Acceleration for Value {
+        fn acceleration_vector_as_milli_g(&self) -> Option<AccelerationVector> {
+            self.acceleration
+        }
+    }
+
+    #[test]
+    fn temperature_as_uom_converts_millikelvins_to_kelvin() {
+        let value = Value {
+            temperature: Some(2_733_190),
+            ..Default::default()
+        };
+        assert_eq!(
+            value.temperature_as_uom(),
+            Some(ThermodynamicTemperature::new::<kelvin>(2_733.19))
+        );
+    }
+
+    #[test]
+    fn temperature_as_uom_is_none_without_a_reading() {
+        let value = Value::default();
+        assert_eq!(value.temperature_as_uom(), None);
+    }
+
+    #[test]
+    fn pressure_as_uom_converts_pascals() {
+        let value = Value {
+            pressure: Some(63_656),
+            ..Default::default()
+        };
+        assert_eq!(
+            value.pressure_as_uom(),
+            Some(UomPressure::new::<pascal>(63_656.0))
+        );
+    }
+
+    #[test]
+    fn pressure_as_uom_is_none_without_a_reading() {
+        let value = Value::default();
+        assert_eq!(value.pressure_as_uom(), None);
+    }
+
+    #[test]
+    fn battery_potential_as_uom_converts_millivolts() {
+        let value = Value {
+            battery_potential: Some(2182),
+            ..Default::default()
+        };
+        assert_eq!(
+            value.battery_potential_as_uom(),
+            Some(ElectricPotential::new::<millivolt>(2182.0))
+        );
+    }
+
+    #[test]
+    fn battery_potential_as_uom_is_none_without_a_reading() {
+        let value = Value::default();
+        assert_eq!(value.battery_potential_as_uom(), None);
+    }
+
+    #[test]
+    fn tx_power_as_uom_converts_dbm_to_a_linear_ratio() {
+        let value = Value {
+            tx_power: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(value.tx_power_as_uom(), Some(Ratio::new::<ratio>(100.0)));
+    }
+
+    #[test]
+    fn tx_power_as_uom_is_none_without_a_reading() {
+        let value = Value::default();
+        assert_eq!(value.tx_power_as_uom(), None);
+    }
+
+    #[test]
+    fn acceleration_vector_as_uom_converts_milli_g_to_standard_gravity() {
+        let value = Value {
+            acceleration: Some(AccelerationVector(1000, -500, 0)),
+            ..Default::default()
+        };
+        assert_eq!(
+            value.acceleration_vector_as_uom(),
+            Some((
+                UomAcceleration::new::<standard_gravity>(1.0),
+                UomAcceleration::new::<standard_gravity>(-0.5),
+                UomAcceleration::new::<standard_gravity>(0.0),
+            ))
+        );
+    }
+
+    #[test]
+    fn acceleration_vector_as_uom_is_none_without_a_reading() {
+        let value = Value::default();
+        assert_eq!(value.acceleration_vector_as_uom(), None);
+    }
+}